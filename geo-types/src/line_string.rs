@@ -1,4 +1,5 @@
 use crate::{Coordinate, CoordinateType, Line, Point, Triangle};
+use std::collections::BinaryHeap;
 use std::iter::FromIterator;
 use std::ops::{Index, IndexMut};
 
@@ -172,6 +173,57 @@ impl<T: CoordinateType> LineString<T> {
         })
     }
 
+    /// Split the `LineString` into overlapping sub-linestrings of at most
+    /// `size` coordinates each, so that work on a huge path can be bounded and
+    /// parallelized (feeding each chunk into an `rstar` index rather than one
+    /// giant envelope, for instance).
+    ///
+    /// Consecutive chunks share one boundary coordinate, so the sub-linestrings
+    /// reassemble into the original without dropping any segment. The final
+    /// chunk holds whatever coordinates remain, even if fewer than `size`, and a
+    /// `LineString` with fewer than two coordinates yields at most itself as a
+    /// single chunk. A `size` below 2 is treated as 2.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::LineString;
+    ///
+    /// let line_string: LineString<f32> =
+    ///     vec![(0., 0.), (1., 0.), (2., 0.), (3., 0.), (4., 0.)].into();
+    /// let chunks: Vec<_> = line_string.chunks(3).collect();
+    /// assert_eq!(chunks.len(), 2);
+    /// assert_eq!(chunks[0], vec![(0., 0.), (1., 0.), (2., 0.)].into());
+    /// assert_eq!(chunks[1], vec![(2., 0.), (3., 0.), (4., 0.)].into());
+    /// ```
+    pub fn chunks(&self, size: usize) -> impl Iterator<Item = LineString<T>> + '_ {
+        let n = self.0.len();
+        // Each chunk overlaps the previous one by a single coordinate, so a
+        // chunk of `size` coordinates advances the window by `size - 1`.
+        let size = size.max(2);
+        let mut start = 0;
+        let mut finished = false;
+        // Each chunk is built on demand so that only one sub-path is ever held
+        // in memory at a time, never the whole fanned-out path.
+        std::iter::from_fn(move || {
+            if finished {
+                return None;
+            }
+            if n < 2 {
+                finished = true;
+                return if n == 1 { Some(self.clone()) } else { None };
+            }
+            let end = (start + size).min(n);
+            let chunk = LineString(self.0[start..end].to_vec());
+            if end == n {
+                finished = true;
+            } else {
+                start = end - 1;
+            }
+            Some(chunk)
+        })
+    }
+
     /// Close the `LineString`. Specifically, if the `LineString` has is at least one coordinate,
     /// and the value of the first coordinate does not equal the value of the last coordinate, then
     /// a new coordinate is added to the end with the value of the first coordinate.
@@ -214,6 +266,380 @@ impl<T: CoordinateType> LineString<T> {
     }
 }
 
+impl<T> LineString<T>
+where
+    T: ::num_traits::Float,
+{
+    /// Returns `true` if the `LineString` is _simple_, i.e. it has no
+    /// self-intersections other than the shared endpoints of consecutive
+    /// segments (and, for a closed ring, the shared first/last coordinate).
+    ///
+    /// This is a prerequisite for trusting any predicate or boolean operation
+    /// on the linestring: the [validity](#validity) rules require a closed
+    /// `LineString` not to self intersect, but that invariant is not otherwise
+    /// enforced.
+    ///
+    /// The check uses a [Bentley–Ottmann] sweep-line rather than the naive
+    /// `O(n²)` pairwise test, so it stays usable on dense polylines.
+    ///
+    /// [Bentley–Ottmann]: https://en.wikipedia.org/wiki/Bentley%E2%80%93Ottmann_algorithm
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::LineString;
+    ///
+    /// let simple: LineString<f64> = vec![(0., 0.), (5., 0.), (5., 5.)].into();
+    /// assert!(simple.is_simple());
+    ///
+    /// let bowtie: LineString<f64> =
+    ///     vec![(0., 0.), (5., 5.), (5., 0.), (0., 5.), (0., 0.)].into();
+    /// assert!(!bowtie.is_simple());
+    /// ```
+    pub fn is_simple(&self) -> bool {
+        self.self_intersections().is_empty()
+    }
+
+    /// Returns the points at which the `LineString` intersects itself.
+    ///
+    /// The endpoint shared by two consecutive segments is not a
+    /// self-intersection, and neither is the first/last coordinate of a closed
+    /// ring; every other intersection — including exactly collinear, partially
+    /// overlapping segments — is reported. The companion [`is_simple`] method
+    /// is simply a test for the emptiness of this set.
+    ///
+    /// [`is_simple`]: #method.is_simple
+    pub fn self_intersections(&self) -> Vec<Coordinate<T>> {
+        sweep::self_intersections(&self.0)
+    }
+
+    /// Simplify the `LineString` with the [Ramer–Douglas–Peucker] algorithm,
+    /// dropping vertices that lie within `epsilon` of the line between the
+    /// vertices that are kept.
+    ///
+    /// The first and last coordinates are always retained; a `LineString` with
+    /// fewer than three coordinates is returned unchanged. If the input
+    /// [`is_closed`], so is the result.
+    ///
+    /// [Ramer–Douglas–Peucker]: https://en.wikipedia.org/wiki/Ramer%E2%80%93Douglas%E2%80%93Peucker_algorithm
+    /// [`is_closed`]: #method.is_closed
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::LineString;
+    ///
+    /// let line_string: LineString<f64> =
+    ///     vec![(0., 0.), (1., 0.1), (2., -0.1), (3., 0.), (10., 0.)].into();
+    /// let simplified = line_string.simplify(1.0);
+    /// assert_eq!(simplified, vec![(0., 0.), (10., 0.)].into());
+    /// ```
+    pub fn simplify(&self, epsilon: T) -> LineString<T> {
+        if self.0.len() < 3 {
+            return self.clone();
+        }
+        let mut keep = vec![false; self.0.len()];
+        let last = self.0.len() - 1;
+        keep[0] = true;
+        keep[last] = true;
+        if self.is_closed() {
+            // On a closed ring `coords[0] == coords[last]`, so the `(0, last)`
+            // base segment is degenerate and RDP would fall back to "farthest
+            // from the start point". Anchor the ring at the vertex farthest from
+            // the start instead, and simplify the two resulting open sub-paths.
+            let mut pivot = 0;
+            let mut max_dist = T::zero();
+            for k in 1..last {
+                let d = perpendicular_distance(self.0[k], self.0[0], self.0[0]);
+                if d > max_dist {
+                    max_dist = d;
+                    pivot = k;
+                }
+            }
+            if pivot != 0 {
+                keep[pivot] = true;
+                rdp(&self.0, 0, pivot, epsilon, &mut keep);
+                rdp(&self.0, pivot, last, epsilon, &mut keep);
+            }
+        } else {
+            rdp(&self.0, 0, last, epsilon, &mut keep);
+        }
+        let mut out: Vec<Coordinate<T>> = self
+            .0
+            .iter()
+            .zip(keep)
+            .filter_map(|(c, k)| if k { Some(*c) } else { None })
+            .collect();
+        preserve_closure(self, &mut out);
+        LineString(out)
+    }
+
+    /// Simplify the `LineString` with the [Visvalingam–Whyatt] algorithm,
+    /// repeatedly removing the vertex whose triangle with its two neighbors has
+    /// the smallest area until a `target` is reached.
+    ///
+    /// The `target` caps the simplification from either end: vertices keep being
+    /// removed until no more than [`target.max_coords`] remain, or until the
+    /// smallest remaining triangle area would exceed [`target.min_area`],
+    /// whichever is hit first. The first and last coordinates are always
+    /// retained; a `LineString` with fewer than three coordinates is returned
+    /// unchanged, and closure is preserved when the input [`is_closed`].
+    ///
+    /// [Visvalingam–Whyatt]: https://en.wikipedia.org/wiki/Visvalingam%E2%80%93Whyatt_algorithm
+    /// [`target.max_coords`]: struct.SimplifyTarget.html#structfield.max_coords
+    /// [`target.min_area`]: struct.SimplifyTarget.html#structfield.min_area
+    /// [`is_closed`]: #method.is_closed
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{LineString, SimplifyTarget};
+    ///
+    /// let line_string: LineString<f64> =
+    ///     vec![(0., 0.), (1., 0.1), (2., -0.1), (3., 0.), (10., 0.)].into();
+    /// let simplified = line_string.simplify_vw(SimplifyTarget::max_coords(2));
+    /// assert_eq!(simplified, vec![(0., 0.), (10., 0.)].into());
+    /// ```
+    pub fn simplify_vw(&self, target: SimplifyTarget<T>) -> LineString<T> {
+        if self.0.len() < 3 {
+            return self.clone();
+        }
+        let mut out = visvalingam_whyatt(&self.0, target);
+        preserve_closure(self, &mut out);
+        LineString(out)
+    }
+
+    /// Return the [convex hull] of the `LineString`'s vertices as a closed ring,
+    /// oriented counter-clockwise.
+    ///
+    /// Unlike the axis-aligned bounding box exposed through the `rstar`
+    /// [`envelope`](#impl-RTreeObject), this is the tightest convex boundary of
+    /// the vertices. The hull is computed with Andrew's monotone chain in
+    /// `O(n log n)`.
+    ///
+    /// Degenerate inputs are returned verbatim: fewer than three coordinates
+    /// yield those coordinates unchanged, and an all-collinear input yields its
+    /// two extreme endpoints.
+    ///
+    /// [convex hull]: https://en.wikipedia.org/wiki/Convex_hull
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::LineString;
+    ///
+    /// let line_string: LineString<f64> =
+    ///     vec![(0., 0.), (4., 0.), (4., 4.), (0., 4.), (2., 2.)].into();
+    /// let hull = line_string.convex_hull();
+    /// assert_eq!(
+    ///     hull,
+    ///     vec![(0., 0.), (4., 0.), (4., 4.), (0., 4.), (0., 0.)].into()
+    /// );
+    /// ```
+    pub fn convex_hull(&self) -> LineString<T> {
+        if self.0.len() < 3 {
+            return self.clone();
+        }
+        let mut points = self.0.clone();
+        points.sort_by(|a, b| (OrdFloat(a.x), OrdFloat(a.y)).cmp(&(OrdFloat(b.x), OrdFloat(b.y))));
+        points.dedup();
+        if points.len() < 3 {
+            return LineString(points);
+        }
+
+        // A left turn has a positive cross product; we pop while the last three
+        // points turn right or stay collinear (`<= 0`).
+        let cross = |o: Coordinate<T>, a: Coordinate<T>, b: Coordinate<T>| {
+            (a.x - o.x) * (b.y - o.y) - (a.y - o.y) * (b.x - o.x)
+        };
+
+        let mut lower: Vec<Coordinate<T>> = Vec::with_capacity(points.len());
+        for &p in &points {
+            while lower.len() >= 2
+                && cross(lower[lower.len() - 2], lower[lower.len() - 1], p) <= T::zero()
+            {
+                lower.pop();
+            }
+            lower.push(p);
+        }
+
+        let mut upper: Vec<Coordinate<T>> = Vec::with_capacity(points.len());
+        for &p in points.iter().rev() {
+            while upper.len() >= 2
+                && cross(upper[upper.len() - 2], upper[upper.len() - 1], p) <= T::zero()
+            {
+                upper.pop();
+            }
+            upper.push(p);
+        }
+
+        // Drop the duplicated endpoint shared by the two chains.
+        lower.pop();
+        upper.pop();
+        lower.extend(upper);
+
+        // All-collinear input collapses to its two extreme endpoints; leave it
+        // open rather than emitting a zero-area ring.
+        if lower.len() >= 3 {
+            lower.push(lower[0]);
+        }
+        LineString(lower)
+    }
+}
+
+/// The stopping criterion for [`LineString::simplify_vw`].
+///
+/// Simplification keeps removing the least-significant vertex until no more
+/// than `max_coords` remain, or until the smallest remaining triangle area
+/// would exceed `min_area` — whichever comes first.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct SimplifyTarget<T: CoordinateType> {
+    /// Stop once at most this many coordinates are left (never below 2).
+    pub max_coords: usize,
+    /// Stop once every remaining vertex's triangle area exceeds this.
+    pub min_area: T,
+}
+
+impl<T: ::num_traits::Float> SimplifyTarget<T> {
+    /// Simplify down to at most `n` coordinates, regardless of area.
+    pub fn max_coords(n: usize) -> Self {
+        SimplifyTarget {
+            max_coords: n,
+            // No area floor: the coordinate count alone governs removal.
+            min_area: T::max_value(),
+        }
+    }
+
+    /// Simplify until every remaining vertex spans a triangle larger than
+    /// `area`, regardless of the resulting coordinate count.
+    pub fn min_area(area: T) -> Self {
+        SimplifyTarget {
+            max_coords: 2,
+            min_area: area,
+        }
+    }
+}
+
+/// Recursively mark the vertices kept by the Ramer–Douglas–Peucker algorithm on
+/// the sub-path `coords[i..=j]`.
+fn rdp<T: ::num_traits::Float>(
+    coords: &[Coordinate<T>],
+    i: usize,
+    j: usize,
+    epsilon: T,
+    keep: &mut [bool],
+) {
+    if j <= i + 1 {
+        return;
+    }
+    let mut max_dist = T::zero();
+    let mut farthest = i;
+    for k in (i + 1)..j {
+        let d = perpendicular_distance(coords[k], coords[i], coords[j]);
+        if d > max_dist {
+            max_dist = d;
+            farthest = k;
+        }
+    }
+    if max_dist > epsilon {
+        keep[farthest] = true;
+        rdp(coords, i, farthest, epsilon, keep);
+        rdp(coords, farthest, j, epsilon, keep);
+    }
+}
+
+/// Perpendicular distance from `p` to the segment `a`–`b`, via the cross-product
+/// formula, falling back to the point-to-point distance when `a == b`.
+fn perpendicular_distance<T: ::num_traits::Float>(
+    p: Coordinate<T>,
+    a: Coordinate<T>,
+    b: Coordinate<T>,
+) -> T {
+    let dx = b.x - a.x;
+    let dy = b.y - a.y;
+    if dx == T::zero() && dy == T::zero() {
+        return ((p.x - a.x).powi(2) + (p.y - a.y).powi(2)).sqrt();
+    }
+    let num = (dy * p.x - dx * p.y + b.x * a.y - b.y * a.x).abs();
+    num / (dx * dx + dy * dy).sqrt()
+}
+
+/// Twice the area of the triangle `a`, `b`, `c` (the effective area keyed on by
+/// Visvalingam–Whyatt; the constant factor does not affect the ordering).
+fn triangle_area<T: ::num_traits::Float>(
+    a: Coordinate<T>,
+    b: Coordinate<T>,
+    c: Coordinate<T>,
+) -> T {
+    ((b.x - a.x) * (c.y - a.y) - (c.x - a.x) * (b.y - a.y)).abs()
+}
+
+/// Remove the least-significant vertices from `coords` until `target` is met,
+/// always retaining the first and last coordinates.
+fn visvalingam_whyatt<T: ::num_traits::Float>(
+    coords: &[Coordinate<T>],
+    target: SimplifyTarget<T>,
+) -> Vec<Coordinate<T>> {
+    let n = coords.len();
+    // Doubly linked list over the surviving vertices, with a per-vertex version
+    // so stale heap entries can be discarded lazily.
+    let mut prev: Vec<usize> = (0..n).map(|i| i.wrapping_sub(1)).collect();
+    let mut next: Vec<usize> = (0..n).map(|i| i + 1).collect();
+    let mut alive = vec![true; n];
+    let mut version = vec![0usize; n];
+
+    // Min-heap on effective area: `Reverse` turns the max-heap into one.
+    let mut heap: BinaryHeap<std::cmp::Reverse<(OrdFloat<T>, usize, usize)>> = BinaryHeap::new();
+    for k in 1..n - 1 {
+        let area = triangle_area(coords[k - 1], coords[k], coords[k + 1]);
+        heap.push(std::cmp::Reverse((OrdFloat(area), k, 0)));
+    }
+
+    // `triangle_area` yields twice the area, so compare against twice the
+    // threshold.
+    let min_area = target.min_area + target.min_area;
+    let max_coords = target.max_coords.max(2);
+    let mut remaining = n;
+
+    while let Some(std::cmp::Reverse((OrdFloat(area), k, ver))) = heap.pop() {
+        if !alive[k] || ver != version[k] {
+            continue; // superseded entry
+        }
+        if remaining <= max_coords || area > min_area {
+            break;
+        }
+        // Remove `k` and splice its neighbors together.
+        let (p, nx) = (prev[k], next[k]);
+        alive[k] = false;
+        remaining -= 1;
+        next[p] = nx;
+        prev[nx] = p;
+        // Recompute the two neighbors' areas (the endpoints never move).
+        for &m in &[p, nx] {
+            if m > 0 && m < n - 1 && alive[m] {
+                version[m] += 1;
+                let new_area = triangle_area(coords[prev[m]], coords[m], coords[next[m]]);
+                heap.push(std::cmp::Reverse((OrdFloat(new_area), m, version[m])));
+            }
+        }
+    }
+
+    (0..n).filter(|&i| alive[i]).map(|i| coords[i]).collect()
+}
+
+/// Re-close `out` when `original` was closed but simplification dropped the
+/// duplicated final coordinate.
+fn preserve_closure<T: ::num_traits::Float>(
+    original: &LineString<T>,
+    out: &mut Vec<Coordinate<T>>,
+) {
+    if original.is_closed() && out.first() != out.last() {
+        if let Some(&first) = out.first() {
+            out.push(first);
+        }
+    }
+}
+
 /// Turn a `Vec` of `Point`-like objects into a `LineString`.
 impl<T: CoordinateType, IC: Into<Coordinate<T>>> From<Vec<IC>> for LineString<T> {
     fn from(v: Vec<IC>) -> Self {
@@ -299,3 +725,473 @@ where
         }
     }
 }
+
+/// A total order over a floating-point value, so it can be used as a key in an
+/// ordered collection or priority queue. `NaN`s compare equal and sort last,
+/// which only ever arises on degenerate (infinite) input.
+#[derive(Clone, Copy, PartialEq)]
+struct OrdFloat<T: ::num_traits::Float>(T);
+
+impl<T: ::num_traits::Float> Eq for OrdFloat<T> {}
+
+impl<T: ::num_traits::Float> PartialOrd for OrdFloat<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: ::num_traits::Float> Ord for OrdFloat<T> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        use std::cmp::Ordering;
+        match self.0.partial_cmp(&other.0) {
+            Some(ord) => ord,
+            // Push NaN to the end; both-NaN is `Equal`.
+            None if self.0.is_nan() && other.0.is_nan() => Ordering::Equal,
+            None if self.0.is_nan() => Ordering::Greater,
+            None => Ordering::Less,
+        }
+    }
+}
+
+/// A Bentley–Ottmann sweep-line used by [`LineString::is_simple`] and
+/// [`LineString::self_intersections`].
+///
+/// The sweep moves a vertical line left-to-right over the event points (segment
+/// endpoints and discovered intersections) held in a priority queue (a
+/// min-[`BinaryHeap`] ordered by `(x, y)`); the status — the segments currently
+/// crossing the line, ordered by their `y` at the *current* sweep `x` — is an
+/// ordered list that is re-compared against that live `x` on every event. Only
+/// vertically adjacent segments in the status can meet at the line, so each
+/// event tests a constant number of neighbor pairs; a crossing swaps the two
+/// segments' order and re-tests their new neighbors.
+mod sweep {
+    use super::{BinaryHeap, Coordinate, OrdFloat};
+    use num_traits::Float;
+    use std::cmp::Ordering;
+    use std::collections::HashSet;
+
+    /// The kind of sweep event, tagged with the segment(s) it concerns.
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    enum Kind {
+        /// Left (smaller `x`) endpoint of segment `s`.
+        Left(usize),
+        /// Right (larger `x`) endpoint of segment `s`.
+        Right(usize),
+        /// Discovered crossing of segments `a` and `b`.
+        Cross(usize, usize),
+    }
+
+    /// An event in the priority queue, ordered so that the [`BinaryHeap`] — a
+    /// max-heap — yields the smallest `(x, y)` first.
+    #[derive(Clone, Copy, PartialEq)]
+    struct Event<T: Float> {
+        x: OrdFloat<T>,
+        y: OrdFloat<T>,
+        kind: Kind,
+    }
+
+    // `OrdFloat` is `Eq` for every `T: Float`, so `Event` is too; derive would
+    // wrongly demand `T: Eq` (which `f64`/`f32` are not).
+    impl<T: Float> Eq for Event<T> {}
+
+    impl<T: Float> PartialOrd for Event<T> {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl<T: Float> std::cmp::Ord for Event<T> {
+        fn cmp(&self, other: &Self) -> Ordering {
+            // Reversed so the heap pops the left-/lowest-most point first.
+            other
+                .x
+                .cmp(&self.x)
+                .then_with(|| other.y.cmp(&self.y))
+        }
+    }
+
+    /// A directed segment with its endpoints normalised so that `a` is the left
+    /// (smaller `x`, ties broken by `y`) endpoint.
+    #[derive(Clone, Copy)]
+    struct Segment<T: Float> {
+        a: Coordinate<T>,
+        b: Coordinate<T>,
+    }
+
+    impl<T: Float> Segment<T> {
+        fn new(p: Coordinate<T>, q: Coordinate<T>) -> Self {
+            if (p.x, p.y) <= (q.x, q.y) {
+                Segment { a: p, b: q }
+            } else {
+                Segment { a: q, b: p }
+            }
+        }
+
+        /// The `y` at which this segment crosses the vertical line `x`, used to
+        /// order the sweep status. Vertical segments report their lower end.
+        fn y_at(&self, x: T) -> T {
+            let dx = self.b.x - self.a.x;
+            if dx == T::zero() {
+                self.a.y.min(self.b.y)
+            } else {
+                let t = (x - self.a.x) / dx;
+                self.a.y + t * (self.b.y - self.a.y)
+            }
+        }
+    }
+
+    fn cross<T: Float>(o: Coordinate<T>, p: Coordinate<T>, q: Coordinate<T>) -> T {
+        (p.x - o.x) * (q.y - o.y) - (p.y - o.y) * (q.x - o.x)
+    }
+
+    fn on_segment<T: Float>(p: Coordinate<T>, seg: &Segment<T>) -> bool {
+        p.x >= seg.a.x.min(seg.b.x)
+            && p.x <= seg.a.x.max(seg.b.x)
+            && p.y >= seg.a.y.min(seg.b.y)
+            && p.y <= seg.a.y.max(seg.b.y)
+    }
+
+    /// Geometric intersection of two segments. Returns a representative point
+    /// when they meet, distinguishing a single crossing/touch from a collinear
+    /// overlap (which must also be reported as an intersection).
+    fn intersection<T: Float>(s1: &Segment<T>, s2: &Segment<T>) -> Option<Coordinate<T>> {
+        let d1 = cross(s1.a, s1.b, s2.a);
+        let d2 = cross(s1.a, s1.b, s2.b);
+        let d3 = cross(s2.a, s2.b, s1.a);
+        let d4 = cross(s2.a, s2.b, s1.b);
+
+        if ((d1 > T::zero()) != (d2 > T::zero()))
+            && ((d3 > T::zero()) != (d4 > T::zero()))
+            && d1 != T::zero()
+            && d2 != T::zero()
+            && d3 != T::zero()
+            && d4 != T::zero()
+        {
+            // Proper crossing: solve for the point.
+            let denom = (s1.b.x - s1.a.x) * (s2.b.y - s2.a.y)
+                - (s1.b.y - s1.a.y) * (s2.b.x - s2.a.x);
+            let t = ((s2.a.x - s1.a.x) * (s2.b.y - s2.a.y)
+                - (s2.a.y - s1.a.y) * (s2.b.x - s2.a.x))
+                / denom;
+            return Some(Coordinate {
+                x: s1.a.x + t * (s1.b.x - s1.a.x),
+                y: s1.a.y + t * (s1.b.y - s1.a.y),
+            });
+        }
+
+        // Collinear or touching-at-an-endpoint cases.
+        if d1 == T::zero() && on_segment(s2.a, s1) {
+            return Some(s2.a);
+        }
+        if d2 == T::zero() && on_segment(s2.b, s1) {
+            return Some(s2.b);
+        }
+        if d3 == T::zero() && on_segment(s1.a, s2) {
+            return Some(s1.a);
+        }
+        if d4 == T::zero() && on_segment(s1.b, s2) {
+            return Some(s1.b);
+        }
+        None
+    }
+
+    /// The coordinate that segments `i` and `j` legitimately share as adjacent
+    /// edges of the ring, if any: consecutive edges share their common vertex,
+    /// and in a closed ring the closure segment is adjacent to the first one.
+    /// Non-adjacent segments share nothing, so any touch between them is a real
+    /// self-intersection.
+    fn shared_endpoint<T: Float>(
+        i: usize,
+        j: usize,
+        m: usize,
+        closed: bool,
+        coords: &[Coordinate<T>],
+    ) -> Option<Coordinate<T>> {
+        let (lo, hi) = if i < j { (i, j) } else { (j, i) };
+        if hi - lo == 1 {
+            Some(coords[hi])
+        } else if closed && lo == 0 && hi == m - 1 {
+            Some(coords[0])
+        } else {
+            None
+        }
+    }
+
+    /// Order two segments by their `y` at the sweep line `x`, breaking ties by
+    /// slope so that segments meeting at `x` are ordered as they will be just to
+    /// its right. This is the comparator the status list is kept sorted by, and
+    /// it is always evaluated at the *live* `x` so the neighbor relation is
+    /// correct after the sweep has advanced.
+    fn order_at<T: Float>(segs: &[Segment<T>], i: usize, j: usize, x: T) -> Ordering {
+        let yi = segs[i].y_at(x);
+        let yj = segs[j].y_at(x);
+        OrdFloat(yi)
+            .cmp(&OrdFloat(yj))
+            .then_with(|| OrdFloat(slope(&segs[i])).cmp(&OrdFloat(slope(&segs[j]))))
+            .then_with(|| i.cmp(&j))
+    }
+
+    /// Slope of a segment; vertical segments sort above everything else.
+    fn slope<T: Float>(seg: &Segment<T>) -> T {
+        let dx = seg.b.x - seg.a.x;
+        if dx == T::zero() {
+            T::infinity()
+        } else {
+            (seg.b.y - seg.a.y) / dx
+        }
+    }
+
+    pub(super) fn self_intersections<T: Float>(coords: &[Coordinate<T>]) -> Vec<Coordinate<T>> {
+        let m = coords.len().saturating_sub(1);
+        if m < 2 {
+            return Vec::new();
+        }
+        let closed = coords.first() == coords.last();
+
+        let segs: Vec<Segment<T>> = (0..m)
+            .map(|k| Segment::new(coords[k], coords[k + 1]))
+            .collect();
+
+        let mut queue: BinaryHeap<Event<T>> = BinaryHeap::new();
+        for (k, seg) in segs.iter().enumerate() {
+            queue.push(Event {
+                x: OrdFloat(seg.a.x),
+                y: OrdFloat(seg.a.y),
+                kind: Kind::Left(k),
+            });
+            queue.push(Event {
+                x: OrdFloat(seg.b.x),
+                y: OrdFloat(seg.b.y),
+                kind: Kind::Right(k),
+            });
+        }
+
+        // Status: the segments currently crossing the sweep line, bottom to top.
+        // It is a plain list kept sorted by `order_at` against the live `x`;
+        // `reported`/`scheduled` deduplicate the pairs we have already recorded
+        // or queued as crossings (two straight segments cross at most once).
+        let mut status: Vec<usize> = Vec::new();
+        let mut found: Vec<Coordinate<T>> = Vec::new();
+        let mut reported: HashSet<(usize, usize)> = HashSet::new();
+        let mut scheduled: HashSet<(usize, usize)> = HashSet::new();
+
+        let pair = |a: usize, b: usize| if a < b { (a, b) } else { (b, a) };
+
+        while let Some(ev) = queue.pop() {
+            let x = ev.x.0;
+            match ev.kind {
+                Kind::Left(s) => {
+                    let at = status
+                        .binary_search_by(|&o| order_at(&segs, o, s, x))
+                        .unwrap_or_else(|i| i);
+                    status.insert(at, s);
+                    if at > 0 {
+                        test(
+                            status[at - 1],
+                            s,
+                            x,
+                            &segs,
+                            coords,
+                            m,
+                            closed,
+                            &mut found,
+                            &mut reported,
+                            &mut scheduled,
+                            &mut queue,
+                        );
+                    }
+                    if at + 1 < status.len() {
+                        test(
+                            s,
+                            status[at + 1],
+                            x,
+                            &segs,
+                            coords,
+                            m,
+                            closed,
+                            &mut found,
+                            &mut reported,
+                            &mut scheduled,
+                            &mut queue,
+                        );
+                    }
+                }
+                Kind::Right(s) => {
+                    if let Some(at) = status.iter().position(|&o| o == s) {
+                        status.remove(at);
+                        if at > 0 && at < status.len() {
+                            test(
+                                status[at - 1],
+                                status[at],
+                                x,
+                                &segs,
+                                coords,
+                                m,
+                                closed,
+                                &mut found,
+                                &mut reported,
+                                &mut scheduled,
+                                &mut queue,
+                            );
+                        }
+                    }
+                }
+                Kind::Cross(a, b) => {
+                    scheduled.remove(&pair(a, b));
+                    // Act only while `a` and `b` are still adjacent; swap them
+                    // and test each against its freshly exposed neighbor.
+                    let pa = status.iter().position(|&o| o == a);
+                    let pb = status.iter().position(|&o| o == b);
+                    if let (Some(pa), Some(pb)) = (pa, pb) {
+                        if pa + 1 == pb || pb + 1 == pa {
+                            status.swap(pa, pb);
+                            let (lo, hi) = (pa.min(pb), pa.max(pb));
+                            if lo > 0 {
+                                test(
+                                    status[lo - 1],
+                                    status[lo],
+                                    x,
+                                    &segs,
+                                    coords,
+                                    m,
+                                    closed,
+                                    &mut found,
+                                    &mut reported,
+                                    &mut scheduled,
+                                    &mut queue,
+                                );
+                            }
+                            if hi + 1 < status.len() {
+                                test(
+                                    status[hi],
+                                    status[hi + 1],
+                                    x,
+                                    &segs,
+                                    coords,
+                                    m,
+                                    closed,
+                                    &mut found,
+                                    &mut reported,
+                                    &mut scheduled,
+                                    &mut queue,
+                                );
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        found
+    }
+
+    /// Test segments `a` and `b` for intersection: record a genuine
+    /// self-intersection (once) and, if they cross to the right of the current
+    /// sweep `x`, schedule the crossing event that will swap their order.
+    #[allow(clippy::too_many_arguments)]
+    fn test<T: Float>(
+        a: usize,
+        b: usize,
+        x: T,
+        segs: &[Segment<T>],
+        coords: &[Coordinate<T>],
+        m: usize,
+        closed: bool,
+        found: &mut Vec<Coordinate<T>>,
+        reported: &mut HashSet<(usize, usize)>,
+        scheduled: &mut HashSet<(usize, usize)>,
+        queue: &mut BinaryHeap<Event<T>>,
+    ) {
+        let key = if a < b { (a, b) } else { (b, a) };
+        let p = match intersection(&segs[a], &segs[b]) {
+            Some(p) => p,
+            None => return,
+        };
+
+        // Adjacent ring edges may touch only at the vertex they share; a touch
+        // elsewhere (a doubling-back overlap) is a real self-intersection.
+        let (s1, s2) = (&segs[a], &segs[b]);
+        let overlap = cross(s1.a, s1.b, s2.a) == T::zero()
+            && cross(s1.a, s1.b, s2.b) == T::zero()
+            && ([s2.a, s2.b].iter().any(|&q| q != p && on_segment(q, s1))
+                || [s1.a, s1.b].iter().any(|&q| q != p && on_segment(q, s2)));
+        let legitimate = !overlap && shared_endpoint(a, b, m, closed, coords) == Some(p);
+        if !legitimate && reported.insert(key) {
+            found.push(p);
+        }
+
+        if p.x > x && scheduled.insert(key) {
+            queue.push(Event {
+                x: OrdFloat(p.x),
+                y: OrdFloat(p.y),
+                kind: Kind::Cross(a, b),
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn is_simple_open_and_closed_simple() {
+        // A plain open polyline has no self-intersections.
+        let open: LineString<f64> = vec![(0., 0.), (5., 0.), (5., 5.)].into();
+        assert!(open.is_simple());
+        assert!(open.self_intersections().is_empty());
+
+        // A simple closed square: the closure edge `(0,4)-(0,0)` is adjacent to
+        // segment 0 and shares `(0,0)`, which must *not* be reported.
+        let square: LineString<f64> =
+            vec![(0., 0.), (4., 0.), (4., 4.), (0., 4.), (0., 0.)].into();
+        assert!(square.is_simple());
+        assert!(square.self_intersections().is_empty());
+    }
+
+    #[test]
+    fn is_simple_collinear_overlap_non_adjacent() {
+        // Segment 0 `(0,0)-(3,0)` and segment 4 `(5,0)-(2,0)` are both on `y=0`
+        // and partially overlap; being non-adjacent, that overlap is a genuine
+        // self-intersection.
+        let line_string: LineString<f64> =
+            vec![(0., 0.), (3., 0.), (3., 5.), (5., 5.), (5., 0.), (2., 0.)].into();
+        assert!(!line_string.is_simple());
+        assert!(!line_string.self_intersections().is_empty());
+    }
+
+    #[test]
+    fn is_simple_endpoint_touches_interior() {
+        // The final vertex `(2,0)` lands in the interior of the non-adjacent
+        // segment 0 `(0,0)-(4,0)` — a T-touch, which is not simple.
+        let line_string: LineString<f64> = vec![(0., 0.), (4., 0.), (4., 4.), (2., 0.)].into();
+        assert!(!line_string.is_simple());
+        assert_eq!(line_string.self_intersections(), vec![(2., 0.).into()]);
+    }
+
+    #[test]
+    fn simplify_closed_ring_uses_true_rdp() {
+        // The first and last coordinates coincide, so the degenerate `(0, last)`
+        // base must not be used; the collinear midpoint `(2, 0)` is dropped and
+        // closure is preserved.
+        let ring: LineString<f64> =
+            vec![(0., 0.), (2., 0.), (4., 0.), (4., 4.), (0., 0.)].into();
+        let simplified = ring.simplify(1.0);
+        assert_eq!(
+            simplified,
+            vec![(0., 0.), (4., 0.), (4., 4.), (0., 0.)].into()
+        );
+        assert!(simplified.is_closed());
+    }
+
+    #[test]
+    fn simplify_vw_min_area_with_neighbor_recompute() {
+        // Removing the two smallest-area vertices forces the shared neighbor's
+        // area to be recomputed twice (superseding stale heap entries), and the
+        // `min_area` threshold stops the process before the middle peak is lost.
+        let line_string: LineString<f64> =
+            vec![(0., 0.), (1., 0.), (2., 1.), (3., 0.), (4., 0.)].into();
+        let simplified = line_string.simplify_vw(SimplifyTarget::min_area(1.5));
+        assert_eq!(simplified, vec![(0., 0.), (2., 1.), (4., 0.)].into());
+    }
+}
@@ -0,0 +1,395 @@
+use crate::CoordinateType;
+use std::iter::FromIterator;
+use std::ops::{Index, IndexMut};
+
+/// A three-dimensional coordinate, carrying an elevation / `z` value alongside
+/// the usual `x` and `y`. This is the 3D analogue of
+/// [`Coordinate`](struct.Coordinate.html).
+#[derive(Eq, PartialEq, Clone, Copy, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Coordinate3D<T>
+where
+    T: CoordinateType,
+{
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T: CoordinateType> From<(T, T, T)> for Coordinate3D<T> {
+    fn from(coords: (T, T, T)) -> Self {
+        Coordinate3D {
+            x: coords.0,
+            y: coords.1,
+            z: coords.2,
+        }
+    }
+}
+
+impl<T: CoordinateType> From<[T; 3]> for Coordinate3D<T> {
+    fn from(coords: [T; 3]) -> Self {
+        Coordinate3D {
+            x: coords[0],
+            y: coords[1],
+            z: coords[2],
+        }
+    }
+}
+
+/// A single point in 3D space, the 3D analogue of
+/// [`Point`](struct.Point.html).
+#[derive(Eq, PartialEq, Clone, Copy, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Point3D<T>(pub Coordinate3D<T>)
+where
+    T: CoordinateType;
+
+impl<T: CoordinateType> Point3D<T> {
+    /// Return the `x` (horizontal) component of the point.
+    pub fn x(self) -> T {
+        self.0.x
+    }
+
+    /// Return the `y` (vertical) component of the point.
+    pub fn y(self) -> T {
+        self.0.y
+    }
+
+    /// Return the `z` (elevation) component of the point.
+    pub fn z(self) -> T {
+        self.0.z
+    }
+}
+
+/// A line segment made up of two 3D [`Coordinate3D`s](struct.Coordinate3D.html),
+/// the 3D analogue of [`Line`](struct.Line.html).
+#[derive(Eq, PartialEq, Clone, Copy, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Line3D<T>
+where
+    T: CoordinateType,
+{
+    pub start: Coordinate3D<T>,
+    pub end: Coordinate3D<T>,
+}
+
+impl<T: CoordinateType> Line3D<T> {
+    /// Create a new 3D line segment from two coordinates.
+    pub fn new<C>(start: C, end: C) -> Self
+    where
+        C: Into<Coordinate3D<T>>,
+    {
+        Line3D {
+            start: start.into(),
+            end: end.into(),
+        }
+    }
+}
+
+/// An ordered collection of two or more
+/// [`Coordinate3D`s](struct.Coordinate3D.html), representing a path between
+/// locations in three dimensions. This is the 3D analogue of
+/// [`LineString`](struct.LineString.html); elevation survives the round-trips
+/// that a 2D `LineString` would flatten away.
+#[derive(Eq, PartialEq, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LineString3D<T>(pub Vec<Coordinate3D<T>>)
+where
+    T: CoordinateType;
+
+/// A `Point3D` iterator returned by the `points_iter` method
+pub struct Points3DIter<'a, T: CoordinateType + 'a>(::std::slice::Iter<'a, Coordinate3D<T>>);
+
+impl<'a, T: CoordinateType> Iterator for Points3DIter<'a, T> {
+    type Item = Point3D<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|c| Point3D(*c))
+    }
+}
+
+impl<'a, T: CoordinateType> DoubleEndedIterator for Points3DIter<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back().map(|c| Point3D(*c))
+    }
+}
+
+impl<T: CoordinateType> LineString3D<T> {
+    /// Return an iterator yielding the coordinates of a `LineString3D` as
+    /// `Point3D`s.
+    pub fn points_iter(&self) -> Points3DIter<T> {
+        Points3DIter(self.0.iter())
+    }
+
+    /// Return the coordinates of a `LineString3D` as a `Vec` of `Point3D`s.
+    pub fn into_points(self) -> Vec<Point3D<T>> {
+        self.0.into_iter().map(Point3D).collect()
+    }
+
+    /// Return an iterator yielding one `Line3D` for each line segment
+    /// in the `LineString3D`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{Coordinate3D, Line3D, LineString3D};
+    ///
+    /// let line_string: LineString3D<f64> =
+    ///     vec![(0., 0., 0.), (1., 1., 2.)].into();
+    ///
+    /// let mut lines = line_string.lines();
+    /// assert_eq!(
+    ///     Some(Line3D::new(
+    ///         Coordinate3D { x: 0., y: 0., z: 0. },
+    ///         Coordinate3D { x: 1., y: 1., z: 2. }
+    ///     )),
+    ///     lines.next()
+    /// );
+    /// assert!(lines.next().is_none());
+    /// ```
+    pub fn lines<'a>(&'a self) -> impl ExactSizeIterator + Iterator<Item = Line3D<T>> + 'a {
+        self.0.windows(2).map(|w| {
+            // slice::windows(N) is guaranteed to yield a slice with exactly N elements
+            unsafe { Line3D::new(*w.get_unchecked(0), *w.get_unchecked(1)) }
+        })
+    }
+
+    /// Close the `LineString3D`. Specifically, if the `LineString3D` has at
+    /// least one coordinate, and the value of the first coordinate does not
+    /// equal the value of the last coordinate, then a new coordinate is added to
+    /// the end with the value of the first coordinate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::LineString3D;
+    ///
+    /// let mut line_string: LineString3D<f64> =
+    ///     vec![(0., 0., 0.), (1., 0., 1.), (1., 1., 1.)].into();
+    /// line_string.close();
+    /// assert_eq!(
+    ///     line_string,
+    ///     vec![(0., 0., 0.), (1., 0., 1.), (1., 1., 1.), (0., 0., 0.)].into()
+    /// );
+    /// ```
+    pub fn close(&mut self) {
+        if !self.is_closed() {
+            self.0.push(self.0[0]);
+        }
+    }
+
+    /// Return the number of coordinates in the `LineString3D`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::LineString3D;
+    ///
+    /// let line_string: LineString3D<f64> =
+    ///     vec![(0., 0., 0.), (5., 0., 1.), (7., 9., 2.)].into();
+    /// assert_eq!(3, line_string.num_coords());
+    /// ```
+    pub fn num_coords(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Checks if the linestring is closed; i.e. it is either empty or, the first
+    /// and last points are the same.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::LineString3D;
+    ///
+    /// let line_string: LineString3D<f64> =
+    ///     vec![(0., 0., 0.), (5., 0., 1.), (0., 0., 0.)].into();
+    /// assert!(line_string.is_closed());
+    /// ```
+    pub fn is_closed(&self) -> bool {
+        self.0.first() == self.0.last()
+    }
+}
+
+/// Turn a `Vec` of `Coordinate3D`-like objects into a `LineString3D`.
+impl<T: CoordinateType, IC: Into<Coordinate3D<T>>> From<Vec<IC>> for LineString3D<T> {
+    fn from(v: Vec<IC>) -> Self {
+        LineString3D(v.into_iter().map(|c| c.into()).collect())
+    }
+}
+
+/// Turn an iterator of `Coordinate3D`-like objects into a `LineString3D`.
+impl<T: CoordinateType, IC: Into<Coordinate3D<T>>> FromIterator<IC> for LineString3D<T> {
+    fn from_iter<I: IntoIterator<Item = IC>>(iter: I) -> Self {
+        LineString3D(iter.into_iter().map(|c| c.into()).collect())
+    }
+}
+
+/// Iterate over all the [Coordinate3D](struct.Coordinate3D.html)s in this
+/// `LineString3D`.
+impl<T: CoordinateType> IntoIterator for LineString3D<T> {
+    type Item = Coordinate3D<T>;
+    type IntoIter = ::std::vec::IntoIter<Coordinate3D<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+/// Mutably iterate over all the [Coordinate3D](struct.Coordinate3D.html)s in
+/// this `LineString3D`.
+impl<'a, T: CoordinateType> IntoIterator for &'a mut LineString3D<T> {
+    type Item = &'a mut Coordinate3D<T>;
+    type IntoIter = ::std::slice::IterMut<'a, Coordinate3D<T>>;
+
+    fn into_iter(self) -> ::std::slice::IterMut<'a, Coordinate3D<T>> {
+        self.0.iter_mut()
+    }
+}
+
+impl<T: CoordinateType> Index<usize> for LineString3D<T> {
+    type Output = Coordinate3D<T>;
+
+    fn index(&self, index: usize) -> &Coordinate3D<T> {
+        self.0.index(index)
+    }
+}
+
+impl<T: CoordinateType> IndexMut<usize> for LineString3D<T> {
+    fn index_mut(&mut self, index: usize) -> &mut Coordinate3D<T> {
+        self.0.index_mut(index)
+    }
+}
+
+#[cfg(feature = "rstar")]
+impl<T> ::rstar::RTreeObject for LineString3D<T>
+where
+    T: ::num_traits::Float + ::rstar::RTreeNum,
+{
+    type Envelope = ::rstar::AABB<[T; 3]>;
+
+    fn envelope(&self) -> Self::Envelope {
+        use num_traits::Bounded;
+        if self.0.is_empty() {
+            return ::rstar::AABB::from_corners(
+                [Bounded::min_value(); 3],
+                [Bounded::max_value(); 3],
+            );
+        }
+        let mut min = [T::max_value(); 3];
+        let mut max = [T::min_value(); 3];
+        for c in &self.0 {
+            for (i, v) in [c.x, c.y, c.z].iter().enumerate() {
+                if *v < min[i] {
+                    min[i] = *v;
+                }
+                if *v > max[i] {
+                    max[i] = *v;
+                }
+            }
+        }
+        ::rstar::AABB::from_corners(min, max)
+    }
+}
+
+#[cfg(feature = "rstar")]
+impl<T> ::rstar::PointDistance for LineString3D<T>
+where
+    T: ::num_traits::Float + ::rstar::RTreeNum,
+{
+    fn distance_2(&self, point: &[T; 3]) -> T {
+        let p = Coordinate3D {
+            x: point[0],
+            y: point[1],
+            z: point[2],
+        };
+        self.lines()
+            .map(|line| point_line_squared_distance_3d(p, line))
+            .fold(T::max_value(), |acc, d| if d < acc { d } else { acc })
+    }
+}
+
+/// The squared 3D Euclidean distance from `p` to the segment `line`.
+#[cfg(feature = "rstar")]
+fn point_line_squared_distance_3d<T: ::num_traits::Float>(
+    p: Coordinate3D<T>,
+    line: Line3D<T>,
+) -> T {
+    let (a, b) = (line.start, line.end);
+    let (dx, dy, dz) = (b.x - a.x, b.y - a.y, b.z - a.z);
+    let len2 = dx * dx + dy * dy + dz * dz;
+    let t = if len2 == T::zero() {
+        T::zero()
+    } else {
+        (((p.x - a.x) * dx + (p.y - a.y) * dy + (p.z - a.z) * dz) / len2)
+            .max(T::zero())
+            .min(T::one())
+    };
+    let (cx, cy, cz) = (a.x + t * dx, a.y + t * dy, a.z + t * dz);
+    (p.x - cx).powi(2) + (p.y - cy).powi(2) + (p.z - cz).powi(2)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn coordinate_conversions() {
+        let from_tuple: Coordinate3D<f64> = (1., 2., 3.).into();
+        let from_array: Coordinate3D<f64> = [1., 2., 3.].into();
+        assert_eq!(from_tuple, Coordinate3D { x: 1., y: 2., z: 3. });
+        assert_eq!(from_array, from_tuple);
+
+        // The `From<Vec<_>>` impl threads both through to `LineString3D`.
+        let from_tuples: LineString3D<f64> = vec![(0., 0., 0.), (1., 2., 3.)].into();
+        let from_arrays: LineString3D<f64> = vec![[0., 0., 0.], [1., 2., 3.]].into();
+        assert_eq!(from_tuples, from_arrays);
+    }
+
+    #[test]
+    fn lines_yields_each_segment() {
+        let line_string: LineString3D<f64> =
+            vec![(0., 0., 0.), (1., 1., 2.), (2., 0., 4.)].into();
+        let lines: Vec<_> = line_string.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(
+            lines[0],
+            Line3D::new((0., 0., 0.), (1., 1., 2.))
+        );
+        assert_eq!(
+            lines[1],
+            Line3D::new((1., 1., 2.), (2., 0., 4.))
+        );
+    }
+
+    #[test]
+    fn close_appends_first_coordinate() {
+        let mut line_string: LineString3D<f64> =
+            vec![(0., 0., 0.), (1., 0., 1.), (1., 1., 1.)].into();
+        line_string.close();
+        assert!(line_string.is_closed());
+        assert_eq!(line_string.num_coords(), 4);
+        // Closing an already-closed linestring is a no-op.
+        line_string.close();
+        assert_eq!(line_string.num_coords(), 4);
+    }
+
+    #[cfg(feature = "rstar")]
+    #[test]
+    fn distance_2_is_squared_3d_euclidean() {
+        use rstar::PointDistance;
+
+        let line_string: LineString3D<f64> =
+            vec![(0., 0., 0.), (10., 0., 0.)].into();
+        // Point straight "above" the segment in z: squared distance is 3² = 9.
+        assert_eq!(line_string.distance_2(&[5., 0., 3.]), 9.);
+        // Beyond the end, the nearest point is the endpoint `(10, 0, 0)`.
+        assert_eq!(line_string.distance_2(&[13., 0., 4.]), 25.);
+
+        // With no segments there is nothing to measure, so the fold returns
+        // `T::max_value()`.
+        let empty: LineString3D<f64> = Vec::<[f64; 3]>::new().into();
+        assert_eq!(empty.distance_2(&[0., 0., 0.]), f64::MAX);
+        let single: LineString3D<f64> = vec![(1., 1., 1.)].into();
+        assert_eq!(single.distance_2(&[0., 0., 0.]), f64::MAX);
+    }
+}